@@ -7,6 +7,7 @@
 use pyo3::prelude::*;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
+use std::rc::Rc;
 
 // =============================================================================
 // BitSet Implementation (arbitrary size using Vec<u64>)
@@ -41,29 +42,26 @@ impl BitSet {
         BitSet { words, n_bits }
     }
 
-    /// Create a bitset from a u128 value (for small bitsets).
-    fn from_u128(value: u128, n_bits: usize) -> Self {
+    /// Create a bitset from a LSB-first word-list (arbitrary width).
+    fn from_words(words: &[u64], n_bits: usize) -> Self {
         let n_words = (n_bits + 63) / 64;
-        let mut words = vec![0u64; n_words];
-        if n_words >= 1 {
-            words[0] = value as u64;
+        let mut result_words = vec![0u64; n_words];
+        for (i, &w) in words.iter().take(n_words).enumerate() {
+            result_words[i] = w;
         }
-        if n_words >= 2 {
-            words[1] = (value >> 64) as u64;
+        // Mask off extra bits in the last word
+        if n_bits % 64 != 0 && n_words > 0 {
+            result_words[n_words - 1] &= (1u64 << (n_bits % 64)) - 1;
+        }
+        BitSet {
+            words: result_words,
+            n_bits,
         }
-        BitSet { words, n_bits }
     }
 
-    /// Convert to u128 (for small bitsets).
-    fn to_u128(&self) -> u128 {
-        let mut result: u128 = 0;
-        if !self.words.is_empty() {
-            result |= self.words[0] as u128;
-        }
-        if self.words.len() >= 2 {
-            result |= (self.words[1] as u128) << 64;
-        }
-        result
+    /// Convert to a LSB-first word-list.
+    fn to_words(&self) -> Vec<u64> {
+        self.words.clone()
     }
 
     /// Check if the bitset is empty (all zeros).
@@ -76,15 +74,6 @@ impl BitSet {
         self.words.iter().map(|w| w.count_ones() as usize).sum()
     }
 
-    /// Get bit at position i.
-    fn get(&self, i: usize) -> bool {
-        if i >= self.n_bits {
-            false
-        } else {
-            (self.words[i / 64] >> (i % 64)) & 1 != 0
-        }
-    }
-
     /// Set bit at position i.
     fn set(&mut self, i: usize) {
         if i < self.n_bits {
@@ -115,13 +104,68 @@ impl BitSet {
         result
     }
 
-    /// Bitwise OR.
-    fn or(&self, other: &BitSet) -> Self {
-        let mut result = BitSet::new(self.n_bits);
-        for (i, (&a, &b)) in self.words.iter().zip(other.words.iter()).enumerate() {
-            result.words[i] = a | b;
+    /// In-place AND against a `ChunkedBitSet`, short-circuiting whole chunks:
+    /// `Zeros` chunks zero out the corresponding words in one pass, `Ones`
+    /// chunks are a no-op, and only `Mixed` chunks touch individual words.
+    /// Returns `true` iff any word changed.
+    fn intersect_assign_chunked(&mut self, other: &ChunkedBitSet) -> bool {
+        let mut changed = false;
+        for (chunk_idx, chunk) in other.chunks.iter().enumerate() {
+            let word_start = chunk_idx * CHUNK_WORDS;
+            let word_end = (word_start + CHUNK_WORDS).min(self.words.len());
+            match chunk {
+                Chunk::Ones(_) => {}
+                Chunk::Zeros => {
+                    for w in self.words[word_start..word_end].iter_mut() {
+                        if *w != 0 {
+                            *w = 0;
+                            changed = true;
+                        }
+                    }
+                }
+                Chunk::Mixed(words) => {
+                    for (w, &word) in words.iter().enumerate() {
+                        if word_start + w < self.words.len() {
+                            let a = &mut self.words[word_start + w];
+                            let new = *a & word;
+                            if new != *a {
+                                *a = new;
+                                changed = true;
+                            }
+                        }
+                    }
+                }
+            }
         }
-        result
+        changed
+    }
+
+    /// In-place OR; mutates `self.words` word-by-word and returns `true` iff
+    /// any word changed. Allocates no new `BitSet`.
+    fn union_assign(&mut self, other: &BitSet) -> bool {
+        let mut changed = false;
+        for (a, &b) in self.words.iter_mut().zip(other.words.iter()) {
+            let new = *a | b;
+            if new != *a {
+                *a = new;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// In-place AND-NOT (`self &= !other`); mutates `self.words` word-by-word
+    /// and returns `true` iff any word changed.
+    fn subtract_assign(&mut self, other: &BitSet) -> bool {
+        let mut changed = false;
+        for (a, &b) in self.words.iter_mut().zip(other.words.iter()) {
+            let new = *a & !b;
+            if new != *a {
+                *a = new;
+                changed = true;
+            }
+        }
+        changed
     }
 
     /// Subtract 1 from the bitset (for computing j_mask = j_property - 1).
@@ -153,20 +197,53 @@ impl BitSet {
     }
 
     /// Iterate over set bit positions.
+    ///
+    /// Walks each word by repeatedly taking `trailing_zeros()` and clearing
+    /// the lowest set bit, so it emits exactly `count()` positions per word
+    /// instead of testing all 64 bits.
     fn iter_bits(&self) -> impl Iterator<Item = usize> + '_ {
-        let n_bits = self.n_bits;
-        self.words.iter().enumerate().flat_map(move |(word_idx, &word)| {
+        self.words.iter().enumerate().flat_map(|(word_idx, &word)| {
             let base = word_idx * 64;
-            (0..64).filter_map(move |bit| {
-                if (word >> bit) & 1 != 0 && base + bit < n_bits {
-                    Some(base + bit)
-                } else {
+            let mut w = word;
+            std::iter::from_fn(move || {
+                if w == 0 {
                     None
+                } else {
+                    let bit = w.trailing_zeros() as usize;
+                    w &= w - 1;
+                    Some(base + bit)
                 }
             })
         })
     }
 
+    /// Position of the lowest set bit, or `None` if empty.
+    fn first_set(&self) -> Option<usize> {
+        self.next_set(0)
+    }
+
+    /// Position of the lowest set bit at or after `from`, or `None` if there
+    /// is none. Lets callers walk set bits directly instead of testing
+    /// every position in a range.
+    fn next_set(&self, from: usize) -> Option<usize> {
+        if from >= self.n_bits {
+            return None;
+        }
+        let mut word_idx = from / 64;
+        let mut w = self.words[word_idx] & (u64::MAX << (from % 64));
+        loop {
+            if w != 0 {
+                let pos = word_idx * 64 + w.trailing_zeros() as usize;
+                return if pos < self.n_bits { Some(pos) } else { None };
+            }
+            word_idx += 1;
+            if word_idx >= self.words.len() {
+                return None;
+            }
+            w = self.words[word_idx];
+        }
+    }
+
     /// Create atomic bitsets (single bit set) for each set bit.
     fn atoms(&self) -> Vec<BitSet> {
         self.iter_bits()
@@ -179,29 +256,137 @@ impl BitSet {
     }
 }
 
+// =============================================================================
+// ChunkedBitSet Implementation (sparse, chunked representation)
+// =============================================================================
+
+/// Number of bits per chunk (32 words), matching rustc's chunked bitset design.
+const CHUNK_BITS: usize = 2048;
+/// Number of `u64` words per chunk.
+const CHUNK_WORDS: usize = CHUNK_BITS / 64;
+
+/// One chunk of a `ChunkedBitSet`: a run of all-zero or all-one bits, or a
+/// genuinely mixed chunk backed by shared words. `Ones` carries its length
+/// because `and_dense` needs it to know how many words to copy through; a
+/// `Zeros` chunk needs no payload since it never contributes any bits, and a
+/// `Mixed` chunk's live-bit count isn't consulted anywhere `and_dense`/
+/// `intersect_assign_chunked` touch words directly instead.
+#[derive(Clone, Debug)]
+enum Chunk {
+    Zeros,
+    Ones(usize),
+    Mixed(Rc<[u64]>),
+}
+
+/// A bitset partitioned into fixed-size chunks, each of which may collapse to
+/// an all-zero or all-one run instead of materializing words. This keeps
+/// memory and AND cost proportional to the nonzero structure of the data
+/// rather than to the full bit width, which matters for FCA contexts over
+/// many objects/properties where the extents/intents are typically sparse.
+#[derive(Clone, Debug)]
+struct ChunkedBitSet {
+    chunks: Vec<Chunk>,
+    n_bits: usize,
+}
+
+impl ChunkedBitSet {
+    /// Number of chunks needed to cover `n_bits`.
+    fn n_chunks(n_bits: usize) -> usize {
+        (n_bits + CHUNK_BITS - 1) / CHUNK_BITS
+    }
+
+    /// Number of live bits in chunk `chunk_idx` (the last chunk may be short).
+    fn chunk_len(n_bits: usize, chunk_idx: usize) -> usize {
+        let start = chunk_idx * CHUNK_BITS;
+        (n_bits - start).min(CHUNK_BITS)
+    }
+
+    /// Create a chunked bitset from a LSB-first word-list (arbitrary width).
+    fn from_words(words: &[u64], n_bits: usize) -> Self {
+        let n_chunks = Self::n_chunks(n_bits);
+        let mut chunks = Vec::with_capacity(n_chunks);
+        for chunk_idx in 0..n_chunks {
+            let len = Self::chunk_len(n_bits, chunk_idx);
+            let word_start = chunk_idx * CHUNK_WORDS;
+            let word_end = (word_start + CHUNK_WORDS).min((n_bits + 63) / 64);
+            let mut chunk_words = vec![0u64; CHUNK_WORDS];
+            for (i, w) in words[word_start.min(words.len())..word_end.min(words.len())]
+                .iter()
+                .enumerate()
+            {
+                chunk_words[i] = *w;
+            }
+            // Mask off bits beyond n_bits in the chunk's last word.
+            if len % 64 != 0 {
+                let last = (len - 1) / 64;
+                chunk_words[last] &= (1u64 << (len % 64)) - 1;
+            }
+            let count: usize = chunk_words.iter().map(|w| w.count_ones() as usize).sum();
+            chunks.push(if count == 0 {
+                Chunk::Zeros
+            } else if count == len {
+                Chunk::Ones(len)
+            } else {
+                Chunk::Mixed(Rc::from(chunk_words))
+            });
+        }
+        ChunkedBitSet { chunks, n_bits }
+    }
+
+    /// AND this chunked bitset against a dense `BitSet`, short-circuiting
+    /// whole chunks (`Zeros` chunks skip entirely, `Ones` chunks copy through,
+    /// only `Mixed` chunks touch individual words), and returning a dense
+    /// result for callers that keep working sets as plain `BitSet`s.
+    fn and_dense(&self, other: &BitSet) -> BitSet {
+        let mut result = BitSet::new(self.n_bits);
+        for (chunk_idx, chunk) in self.chunks.iter().enumerate() {
+            let word_start = chunk_idx * CHUNK_WORDS;
+            match chunk {
+                Chunk::Zeros => {}
+                Chunk::Ones(len) => {
+                    let n_words = len.div_ceil(64);
+                    for w in 0..n_words {
+                        if word_start + w < other.words.len() {
+                            result.words[word_start + w] = other.words[word_start + w];
+                        }
+                    }
+                }
+                Chunk::Mixed(words) => {
+                    for (w, &word) in words.iter().enumerate() {
+                        if word_start + w < other.words.len() {
+                            result.words[word_start + w] = word & other.words[word_start + w];
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
 // =============================================================================
 // FCA Context Structure
 // =============================================================================
 
-/// Formal context with extents and intents as integer vectors.
+/// Formal context with extents and intents as chunked, sparse bitsets.
 struct FcaContext {
     n_objects: usize,
     n_properties: usize,
     /// extents[j] = bitset of objects that have property j
-    extents: Vec<BitSet>,
+    extents: Vec<ChunkedBitSet>,
     /// intents[i] = bitset of properties that object i has
-    intents: Vec<BitSet>,
+    intents: Vec<ChunkedBitSet>,
 }
 
 impl FcaContext {
-    fn new(n_objects: usize, n_properties: usize, extents_raw: Vec<u128>, intents_raw: Vec<u128>) -> Self {
-        let extents: Vec<BitSet> = extents_raw
+    fn new(n_objects: usize, n_properties: usize, extents_raw: Vec<Vec<u64>>, intents_raw: Vec<Vec<u64>>) -> Self {
+        let extents: Vec<ChunkedBitSet> = extents_raw
             .iter()
-            .map(|&e| BitSet::from_u128(e, n_objects))
+            .map(|e| ChunkedBitSet::from_words(e, n_objects))
             .collect();
-        let intents: Vec<BitSet> = intents_raw
+        let intents: Vec<ChunkedBitSet> = intents_raw
             .iter()
-            .map(|&i| BitSet::from_u128(i, n_properties))
+            .map(|i| ChunkedBitSet::from_words(i, n_properties))
             .collect();
         FcaContext {
             n_objects,
@@ -217,7 +402,10 @@ impl FcaContext {
         let mut result = BitSet::supremum(self.n_properties);
         for i in objects.iter_bits() {
             if i < self.intents.len() {
-                result = result.and(&self.intents[i]);
+                result.intersect_assign_chunked(&self.intents[i]);
+                if result.is_empty() {
+                    break;
+                }
             }
         }
         result
@@ -229,7 +417,10 @@ impl FcaContext {
         let mut result = BitSet::supremum(self.n_objects);
         for j in properties.iter_bits() {
             if j < self.extents.len() {
-                result = result.and(&self.extents[j]);
+                result.intersect_assign_chunked(&self.extents[j]);
+                if result.is_empty() {
+                    break;
+                }
             }
         }
         result
@@ -282,16 +473,16 @@ fn neighbors(objects: &BitSet, ctx: &FcaContext) -> Vec<(BitSet, BitSet)> {
     let mut result = Vec::new();
     let mut minimal = objects.complement();
 
-    for i in 0..ctx.n_objects {
-        if !minimal.get(i) {
-            continue;
-        }
-
+    // Walk the set bits of `minimal` directly instead of testing every i
+    // in 0..n_objects.
+    let mut next_i = minimal.first_set();
+    while let Some(i) = next_i {
         // add = atomic bitset with only bit i set
         let mut add = BitSet::new(ctx.n_objects);
         add.set(i);
 
-        let objects_and_add = objects.or(&add);
+        let mut objects_and_add = objects.clone();
+        objects_and_add.union_assign(&add);
         let (extent, intent) = ctx.doubleprime_objects(&objects_and_add);
 
         // Check: extent & ~objects_and_add & minimal
@@ -299,11 +490,13 @@ fn neighbors(objects: &BitSet, ctx: &FcaContext) -> Vec<(BitSet, BitSet)> {
         let check = extent.and(&complement_oaa).and(&minimal);
 
         if !check.is_empty() {
-            // minimal &= ~add
-            minimal = minimal.and(&add.complement());
+            // minimal &= ~add, i.e. drop bit i from minimal
+            minimal.subtract_assign(&add);
         } else {
             result.push((extent, intent));
         }
+
+        next_i = minimal.next_set(i + 1);
     }
 
     result
@@ -311,7 +504,7 @@ fn neighbors(objects: &BitSet, ctx: &FcaContext) -> Vec<(BitSet, BitSet)> {
 
 /// Lindig's lattice generation algorithm.
 /// Returns: Vec<(extent_int, intent_int, upper_indices, lower_indices)>
-fn lindig_lattice(ctx: &FcaContext, infimum: &BitSet) -> Vec<(u128, u128, Vec<usize>, Vec<usize>)> {
+fn lindig_lattice(ctx: &FcaContext, infimum: &BitSet) -> Vec<(Vec<u64>, Vec<u64>, Vec<usize>, Vec<usize>)> {
     let (extent, intent) = ctx.doubleprime_objects(infimum);
 
     let mut concepts: Vec<(BitSet, BitSet, Vec<usize>, Vec<usize>)> = Vec::new();
@@ -368,7 +561,7 @@ fn lindig_lattice(ctx: &FcaContext, infimum: &BitSet) -> Vec<(u128, u128, Vec<us
     // Convert to output format
     concepts
         .into_iter()
-        .map(|(e, i, u, l)| (e.to_u128(), i.to_u128(), u, l))
+        .map(|(e, i, u, l)| (e.to_words(), i.to_words(), u, l))
         .collect()
 }
 
@@ -378,7 +571,7 @@ fn lindig_lattice(ctx: &FcaContext, infimum: &BitSet) -> Vec<(u128, u128, Vec<us
 
 /// FCBO fast_generate_from algorithm.
 /// Generates concepts by intents.
-fn fcbo_fast_generate_from(ctx: &FcaContext) -> Vec<(u128, u128)> {
+fn fcbo_fast_generate_from(ctx: &FcaContext) -> Vec<(Vec<u64>, Vec<u64>)> {
     let n_properties = ctx.n_properties;
 
     // j_atom: list of (index, atomic bitset for property j)
@@ -394,22 +587,26 @@ fn fcbo_fast_generate_from(ctx: &FcaContext) -> Vec<(u128, u128)> {
 
     let initial_concept = ctx.doubleprime_objects(&objects_supremum);
 
-    // Stack: (concept, property_index, property_sets)
-    let mut stack: Vec<((BitSet, BitSet), usize, Vec<BitSet>)> = Vec::new();
-    let initial_property_sets = vec![properties_infimum.clone(); n_properties];
+    // Stack: (concept, property_index, property_sets). property_sets is
+    // shared via Rc: pushing a child frame is a refcount bump, not a
+    // Vec<BitSet> clone, and the vector is only actually duplicated (via
+    // Rc::make_mut) when a frame mutates a slot while the vector is still
+    // shared with a pushed frame.
+    let mut stack: Vec<((BitSet, BitSet), usize, Rc<Vec<BitSet>>)> = Vec::new();
+    let initial_property_sets = Rc::new(vec![properties_infimum.clone(); n_properties]);
     stack.push((initial_concept, 0, initial_property_sets));
 
-    let mut result: Vec<(u128, u128)> = Vec::new();
+    let mut result: Vec<(Vec<u64>, Vec<u64>)> = Vec::new();
 
     while let Some((concept, property_index, property_sets)) = stack.pop() {
         let (extent, intent) = &concept;
-        result.push((extent.to_u128(), intent.to_u128()));
+        result.push((extent.to_words(), intent.to_words()));
 
         if property_index == n_properties || extent.is_empty() {
             continue;
         }
 
-        let mut next_property_sets = property_sets.clone();
+        let mut next_property_sets = property_sets;
 
         // Iterate in reverse order
         for &(j, ref j_property) in j_atom[property_index..].iter().rev() {
@@ -427,7 +624,7 @@ fn fcbo_fast_generate_from(ctx: &FcaContext) -> Vec<(u128, u128)> {
             // if x & intent == x
             if x.and(intent) == x {
                 // j_extent = extent & context._extents[j]
-                let j_extent_bits = extent.and(&ctx.extents[j]);
+                let j_extent_bits = ctx.extents[j].and_dense(extent);
 
                 // j_intent = prime(j_extent)
                 let j_intent = ctx.prime_objects(&j_extent_bits);
@@ -438,9 +635,9 @@ fn fcbo_fast_generate_from(ctx: &FcaContext) -> Vec<(u128, u128)> {
                 // if j_lower & intent == j_lower
                 if j_lower.and(intent) == j_lower {
                     let new_concept = (j_extent_bits.clone(), j_intent.clone());
-                    stack.push((new_concept, j + 1, next_property_sets.clone()));
+                    stack.push((new_concept, j + 1, Rc::clone(&next_property_sets)));
                 } else {
-                    next_property_sets[j] = j_intent;
+                    Rc::make_mut(&mut next_property_sets)[j] = j_intent;
                 }
             }
         }
@@ -451,7 +648,7 @@ fn fcbo_fast_generate_from(ctx: &FcaContext) -> Vec<(u128, u128)> {
 
 /// FCBO dual algorithm.
 /// Generates concepts by extents.
-fn fcbo_dual(ctx: &FcaContext) -> Vec<(u128, u128)> {
+fn fcbo_dual(ctx: &FcaContext) -> Vec<(Vec<u64>, Vec<u64>)> {
     let n_objects = ctx.n_objects;
 
     // j_atom: list of (index, atomic bitset for object j)
@@ -467,22 +664,26 @@ fn fcbo_dual(ctx: &FcaContext) -> Vec<(u128, u128)> {
     // Start with Objects.infimum.doubleprime() = (empty extent, all properties)
     let initial_concept = ctx.doubleprime_objects(&objects_infimum);
 
-    // Stack: (concept, object_index, object_sets)
-    let mut stack: Vec<((BitSet, BitSet), usize, Vec<BitSet>)> = Vec::new();
-    let initial_object_sets = vec![objects_infimum.clone(); n_objects];
+    // Stack: (concept, object_index, object_sets). object_sets is shared via
+    // Rc: pushing a child frame is a refcount bump, not a Vec<BitSet> clone,
+    // and the vector is only actually duplicated (via Rc::make_mut) when a
+    // frame mutates a slot while the vector is still shared with a pushed
+    // frame.
+    let mut stack: Vec<((BitSet, BitSet), usize, Rc<Vec<BitSet>>)> = Vec::new();
+    let initial_object_sets = Rc::new(vec![objects_infimum.clone(); n_objects]);
     stack.push((initial_concept, 0, initial_object_sets));
 
-    let mut result: Vec<(u128, u128)> = Vec::new();
+    let mut result: Vec<(Vec<u64>, Vec<u64>)> = Vec::new();
 
     while let Some((concept, object_index, object_sets)) = stack.pop() {
         let (extent, intent) = &concept;
-        result.push((extent.to_u128(), intent.to_u128()));
+        result.push((extent.to_words(), intent.to_words()));
 
         if object_index == n_objects || intent.is_empty() {
             continue;
         }
 
-        let mut next_object_sets = object_sets.clone();
+        let mut next_object_sets = object_sets;
 
         // Iterate in reverse order
         for &(j, ref j_object) in j_atom[object_index..].iter().rev() {
@@ -500,7 +701,7 @@ fn fcbo_dual(ctx: &FcaContext) -> Vec<(u128, u128)> {
             // if x & extent == x
             if x.and(extent) == x {
                 // j_intent = intent & context._intents[j]
-                let j_intent_bits = intent.and(&ctx.intents[j]);
+                let j_intent_bits = ctx.intents[j].and_dense(intent);
 
                 // j_extent = prime(j_intent)
                 let j_extent = ctx.prime_properties(&j_intent_bits);
@@ -511,9 +712,9 @@ fn fcbo_dual(ctx: &FcaContext) -> Vec<(u128, u128)> {
                 // if j_lower & extent == j_lower
                 if j_lower.and(extent) == j_lower {
                     let new_concept = (j_extent.clone(), j_intent_bits.clone());
-                    stack.push((new_concept, j + 1, next_object_sets.clone()));
+                    stack.push((new_concept, j + 1, Rc::clone(&next_object_sets)));
                 } else {
-                    next_object_sets[j] = j_extent;
+                    Rc::make_mut(&mut next_object_sets)[j] = j_extent;
                 }
             }
         }
@@ -531,22 +732,22 @@ fn fcbo_dual(ctx: &FcaContext) -> Vec<(u128, u128)> {
 /// Args:
 ///     n_objects: Number of objects in the context.
 ///     n_properties: Number of properties in the context.
-///     extents: List of integers representing extents (column bitsets).
-///     intents: List of integers representing intents (row bitsets).
-///     infimum: Starting extent (usually 0 for empty set).
+///     extents: List of word-lists (LSB-first `u64`s) representing extents (column bitsets).
+///     intents: List of word-lists (LSB-first `u64`s) representing intents (row bitsets).
+///     infimum: Starting extent as a word-list (usually empty for the empty set).
 ///
 /// Returns:
-///     List of (extent, intent, upper_indices, lower_indices) tuples.
+///     List of (extent, intent, upper_indices, lower_indices) tuples, extent/intent as word-lists.
 #[pyfunction]
 fn lindig_lattice_py(
     n_objects: usize,
     n_properties: usize,
-    extents: Vec<u128>,
-    intents: Vec<u128>,
-    infimum: u128,
-) -> Vec<(u128, u128, Vec<usize>, Vec<usize>)> {
+    extents: Vec<Vec<u64>>,
+    intents: Vec<Vec<u64>>,
+    infimum: Vec<u64>,
+) -> Vec<(Vec<u64>, Vec<u64>, Vec<usize>, Vec<usize>)> {
     let ctx = FcaContext::new(n_objects, n_properties, extents, intents);
-    let infimum_bitset = BitSet::from_u128(infimum, n_objects);
+    let infimum_bitset = BitSet::from_words(&infimum, n_objects);
     lindig_lattice(&ctx, &infimum_bitset)
 }
 
@@ -555,18 +756,18 @@ fn lindig_lattice_py(
 /// Args:
 ///     n_objects: Number of objects in the context.
 ///     n_properties: Number of properties in the context.
-///     extents: List of integers representing extents (column bitsets).
-///     intents: List of integers representing intents (row bitsets).
+///     extents: List of word-lists (LSB-first `u64`s) representing extents (column bitsets).
+///     intents: List of word-lists (LSB-first `u64`s) representing intents (row bitsets).
 ///
 /// Returns:
-///     List of (extent, intent) tuples.
+///     List of (extent, intent) tuples, extent/intent as word-lists.
 #[pyfunction]
 fn fcbo_fast_generate_from_py(
     n_objects: usize,
     n_properties: usize,
-    extents: Vec<u128>,
-    intents: Vec<u128>,
-) -> Vec<(u128, u128)> {
+    extents: Vec<Vec<u64>>,
+    intents: Vec<Vec<u64>>,
+) -> Vec<(Vec<u64>, Vec<u64>)> {
     let ctx = FcaContext::new(n_objects, n_properties, extents, intents);
     fcbo_fast_generate_from(&ctx)
 }
@@ -576,18 +777,18 @@ fn fcbo_fast_generate_from_py(
 /// Args:
 ///     n_objects: Number of objects in the context.
 ///     n_properties: Number of properties in the context.
-///     extents: List of integers representing extents (column bitsets).
-///     intents: List of integers representing intents (row bitsets).
+///     extents: List of word-lists (LSB-first `u64`s) representing extents (column bitsets).
+///     intents: List of word-lists (LSB-first `u64`s) representing intents (row bitsets).
 ///
 /// Returns:
-///     List of (extent, intent) tuples.
+///     List of (extent, intent) tuples, extent/intent as word-lists.
 #[pyfunction]
 fn fcbo_dual_py(
     n_objects: usize,
     n_properties: usize,
-    extents: Vec<u128>,
-    intents: Vec<u128>,
-) -> Vec<(u128, u128)> {
+    extents: Vec<Vec<u64>>,
+    intents: Vec<Vec<u64>>,
+) -> Vec<(Vec<u64>, Vec<u64>)> {
     let ctx = FcaContext::new(n_objects, n_properties, extents, intents);
     fcbo_dual(&ctx)
 }